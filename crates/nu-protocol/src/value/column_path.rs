@@ -1,9 +1,14 @@
 use crate::Value;
 use derive_new::new;
 use getset::Getters;
-use nu_source::{b, span_for_spanned_list, DebugDocBuilder, HasFallibleSpan, PrettyDebug, Span};
+use nu_errors::ShellError;
+use nu_source::{
+    b, span_for_spanned_list, DebugDocBuilder, HasFallibleSpan, PrettyDebug, Span, Spanned,
+    SpannedItem,
+};
 use num_bigint::BigInt;
 use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 
 /// A PathMember that has yet to be spanned so that it can be used in later processing
 #[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
@@ -61,6 +66,142 @@ impl ColumnPath {
     pub fn split_last(&self) -> Option<(&PathMember, &[PathMember])> {
         self.members.split_last()
     }
+
+    /// Renders the column path the way it would be written, eg) `foo.0.bar`. Column names that
+    /// wouldn't read back as the same member, like `"my key"` or `"0"`, are quoted so the result can be parsed again.
+    pub fn as_string(&self) -> String {
+        self.members
+            .iter()
+            .map(|member| match &member.unspanned {
+                UnspannedPathMember::String(name) => quote_member(name),
+                UnspannedPathMember::Int(n) => format!("{}", n),
+            })
+            .collect::<Vec<String>>()
+            .join(".")
+    }
+
+    /// Parses a dotted path such as `foo.0.bar` into a ColumnPath. Numeric members become row numbers and
+    /// everything else a column name. A member can be quoted to keep dots or digits as part of a column name, eg) `foo."bar.baz"`.
+    /// Inside quotes, a backslash escapes the quote character or another backslash.
+    pub fn build(text: &Spanned<impl AsRef<str>>) -> Result<ColumnPath, ShellError> {
+        let source = text.item.as_ref();
+
+        if source.is_empty() {
+            return Ok(ColumnPath::new(vec![]));
+        }
+
+        let member_span = |start: usize, end: usize| {
+            if text.span.is_unknown() {
+                Span::unknown()
+            } else {
+                Span::new(text.span.start() + start, text.span.start() + end)
+            }
+        };
+
+        let mut members = vec![];
+        let mut current = String::new();
+        let mut quote: Option<char> = None;
+        let mut was_quoted = false;
+        let mut start = 0;
+
+        let mut chars = source.char_indices().peekable();
+
+        while let Some((idx, c)) = chars.next() {
+            match c {
+                '\\' if quote.is_some() => match chars.peek() {
+                    Some(&(_, next)) if Some(next) == quote || next == '\\' => {
+                        current.push(next);
+                        chars.next();
+                    }
+                    _ => current.push(c),
+                },
+                c if Some(c) == quote => quote = None,
+                '"' | '\'' if quote.is_none() && current.is_empty() && !was_quoted => {
+                    quote = Some(c);
+                    was_quoted = true;
+                }
+                '.' if quote.is_none() => {
+                    members.push(build_member(&current, was_quoted, member_span(start, idx))?);
+                    current.clear();
+                    was_quoted = false;
+                    start = idx + 1;
+                }
+                c => current.push(c),
+            }
+        }
+
+        if quote.is_some() {
+            return Err(ShellError::syntax_error(
+                "Unterminated quote in column path".spanned(member_span(start, source.len())),
+            ));
+        }
+
+        members.push(build_member(
+            &current,
+            was_quoted,
+            member_span(start, source.len()),
+        )?);
+
+        Ok(ColumnPath::new(members))
+    }
+}
+
+fn quote_member(name: &str) -> String {
+    let is_bare = !name.is_empty()
+        && BigInt::from_str(name).is_err()
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '-');
+
+    if is_bare {
+        return name.to_string();
+    }
+
+    let quote = if name.contains('"') && !name.contains('\'') {
+        '\''
+    } else {
+        '"'
+    };
+
+    let mut quoted = String::new();
+    quoted.push(quote);
+
+    for c in name.chars() {
+        if c == quote || c == '\\' {
+            quoted.push('\\');
+        }
+
+        quoted.push(c);
+    }
+
+    quoted.push(quote);
+    quoted
+}
+
+fn build_member(text: &str, quoted: bool, span: Span) -> Result<PathMember, ShellError> {
+    if quoted {
+        return Ok(PathMember::string(text, span));
+    }
+
+    if text.is_empty() {
+        return Err(ShellError::syntax_error(
+            "Empty member in column path".spanned(span),
+        ));
+    }
+
+    match BigInt::from_str(text) {
+        Ok(int) => Ok(PathMember::int(int, span)),
+        Err(_) => Ok(PathMember::string(text, span)),
+    }
+}
+
+impl FromStr for ColumnPath {
+    type Err = ShellError;
+
+    /// Parses a dotted path with no span information attached to its members
+    fn from_str(text: &str) -> Result<ColumnPath, ShellError> {
+        ColumnPath::build(&text.spanned_unknown())
+    }
 }
 
 impl PrettyDebug for ColumnPath {
@@ -127,3 +268,95 @@ pub fn did_you_mean(obj_source: &Value, field_tried: &PathMember) -> Option<Vec<
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn members(text: &str) -> Vec<UnspannedPathMember> {
+        ColumnPath::from_str(text)
+            .expect("path should parse")
+            .iter()
+            .map(|member| member.unspanned.clone())
+            .collect()
+    }
+
+    #[test]
+    fn builds_string_and_int_members() {
+        assert_eq!(
+            members("foo.0.bar"),
+            vec![
+                UnspannedPathMember::String("foo".to_string()),
+                UnspannedPathMember::Int(BigInt::from(0)),
+                UnspannedPathMember::String("bar".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn quoted_members_are_always_column_names() {
+        assert_eq!(
+            members(r#"foo."bar.baz"."0""#),
+            vec![
+                UnspannedPathMember::String("foo".to_string()),
+                UnspannedPathMember::String("bar.baz".to_string()),
+                UnspannedPathMember::String("0".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn renders_paths_that_parse_back_to_the_same_members() {
+        let path = ColumnPath::new(vec![
+            PathMember::string("package", Span::unknown()),
+            PathMember::string("my key", Span::unknown()),
+            PathMember::string("a.b", Span::unknown()),
+            PathMember::string("9999", Span::unknown()),
+            PathMember::int(2, Span::unknown()),
+            PathMember::string("say \"hi\"", Span::unknown()),
+        ]);
+
+        let rendered = path.as_string();
+
+        assert_eq!(rendered, r#"package."my key"."a.b"."9999".2.'say "hi"'"#);
+        assert_eq!(
+            members(&rendered),
+            path.iter()
+                .map(|member| member.unspanned.clone())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn escapes_quotes_and_backslashes_that_would_end_a_member() {
+        let path = ColumnPath::new(vec![
+            PathMember::string("it's \"fine\"", Span::unknown()),
+            PathMember::string(r"C:\temp\", Span::unknown()),
+        ]);
+
+        let rendered = path.as_string();
+
+        assert_eq!(rendered, r#""it's \"fine\""."C:\\temp\\""#);
+        assert_eq!(
+            members(&rendered),
+            path.iter()
+                .map(|member| member.unspanned.clone())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_paths() {
+        assert!(ColumnPath::from_str("foo..bar").is_err());
+        assert!(ColumnPath::from_str(r#"foo."bar"#).is_err());
+    }
+
+    #[test]
+    fn member_spans_follow_the_source_text() {
+        let path =
+            ColumnPath::build(&"foo.12".spanned(Span::new(10, 16))).expect("path should parse");
+
+        assert_eq!(path.members()[0].span, Span::new(10, 13));
+        assert_eq!(path.members()[1].span, Span::new(14, 16));
+    }
+}
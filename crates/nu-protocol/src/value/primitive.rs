@@ -5,7 +5,7 @@ use crate::value::{serde_bigdecimal, serde_bigint};
 use bigdecimal::BigDecimal;
 use chrono::{DateTime, Utc};
 use nu_errors::{ExpectedRange, ShellError};
-use nu_source::{PrettyDebug, Span, SpannedItem};
+use nu_source::{Span, SpannedItem};
 use num_bigint::BigInt;
 use num_traits::cast::{FromPrimitive, ToPrimitive};
 use serde::{Deserialize, Serialize};
@@ -149,24 +149,7 @@ pub fn format_primitive(primitive: &Primitive, field_name: Option<&String>) -> S
         Primitive::Pattern(s) => s.to_string(),
         Primitive::String(s) => s.to_owned(),
         Primitive::Line(s) => s.to_owned(),
-        Primitive::ColumnPath(p) => {
-            let mut members = p.iter();
-            let mut f = String::new();
-
-            f.push_str(
-                &members
-                    .next()
-                    .expect("BUG: column path with zero members")
-                    .display(),
-            );
-
-            for member in members {
-                f.push_str(".");
-                f.push_str(&member.display())
-            }
-
-            f
-        }
+        Primitive::ColumnPath(p) => p.as_string(),
         Primitive::Boolean(b) => match (b, field_name) {
             (true, None) => "Yes",
             (false, None) => "No",
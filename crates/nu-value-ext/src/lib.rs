@@ -1,11 +1,11 @@
-use itertools::Itertools;
+use chrono::{DateTime, Datelike, Timelike, Utc};
 use nu_errors::{ExpectedRange, ShellError};
 use nu_protocol::{
-    ColumnPath, MaybeOwned, PathMember, Primitive, ShellTypeName, SpannedTypeName,
+    ColumnPath, Dictionary, MaybeOwned, PathMember, Primitive, ShellTypeName, SpannedTypeName,
     UnspannedPathMember, UntaggedValue, Value,
 };
 use nu_source::{HasSpan, PrettyDebug, Spanned, SpannedItem, Tag, Tagged, TaggedItem};
-use num_traits::cast::ToPrimitive;
+use num_traits::{cast::ToPrimitive, Zero};
 
 pub trait ValueExt {
     fn row_entries(&self) -> RowValueIter<'_>;
@@ -143,14 +143,24 @@ pub fn get_data_by_member(value: &Value, name: &PathMember) -> Result<Value, She
                     let mut out = vec![];
 
                     for item in l {
-                        if let Value {
-                            value: UntaggedValue::Row(o),
-                            ..
-                        } = item
-                        {
-                            if let Some(v) = o.get_data_by_key(string[..].spanned(name.span)) {
-                                out.push(v)
+                        match item {
+                            Value {
+                                value: UntaggedValue::Row(o),
+                                ..
+                            } => {
+                                if let Some(v) = o.get_data_by_key(string[..].spanned(name.span)) {
+                                    out.push(v)
+                                }
                             }
+                            Value {
+                                value: UntaggedValue::Primitive(Primitive::Date(date)),
+                                tag,
+                            } => {
+                                if let Some(v) = get_date_field(date, string) {
+                                    out.push(v.into_value(tag))
+                                }
+                            }
+                            _ => {}
                         }
                     }
 
@@ -179,6 +189,23 @@ pub fn get_data_by_member(value: &Value, name: &PathMember) -> Result<Value, She
                 }
             }
         }
+
+        // If the value is a date, the member is one of its fields
+        UntaggedValue::Primitive(Primitive::Date(date)) => match &name.unspanned {
+            UnspannedPathMember::String(string) => get_date_field(date, string)
+                .map(|v| v.into_value(Tag::new(value.anchor(), name.span)))
+                .ok_or_else(|| {
+                    ShellError::labeled_error(
+                        "Unknown date field",
+                        format!("valid date fields are: {}", DATE_FIELDS.join(", ")),
+                        name.span,
+                    )
+                }),
+            UnspannedPathMember::Int(_) => Err(ShellError::invalid_integer_index(
+                "date".spanned(value.tag.span),
+                name.span,
+            )),
+        },
         other => Err(ShellError::type_error(
             "row or table",
             other.type_name().spanned(value.tag.span),
@@ -186,6 +213,26 @@ pub fn get_data_by_member(value: &Value, name: &PathMember) -> Result<Value, She
     }
 }
 
+/// The fields that can be read from a date, matching the columns produced by the `date` command
+const DATE_FIELDS: [&str; 7] = [
+    "year", "month", "day", "hour", "minute", "second", "timezone",
+];
+
+fn get_date_field(date: &DateTime<Utc>, field: &str) -> Option<UntaggedValue> {
+    let value = match field {
+        "year" => UntaggedValue::int(date.year()),
+        "month" => UntaggedValue::int(date.month()),
+        "day" => UntaggedValue::int(date.day()),
+        "hour" => UntaggedValue::int(date.hour()),
+        "minute" => UntaggedValue::int(date.minute()),
+        "second" => UntaggedValue::int(date.second()),
+        "timezone" => UntaggedValue::string(format!("{}", date.offset())),
+        _ => return None,
+    };
+
+    Some(value)
+}
+
 pub fn get_data_by_column_path(
     value: &Value,
     path: &ColumnPath,
@@ -306,9 +353,29 @@ pub fn insert_data_at_column_path(
 
         let mut current: &mut Value = &mut original;
 
-        for member in front {
+        for (idx, member) in front.iter().enumerate() {
             let type_name = current.spanned_type_name();
 
+            // Create any missing intermediate rows so the new value has somewhere to go. When the
+            // next member is the first row number, a table holding a single row is created instead.
+            if let (UntaggedValue::Row(dict), UnspannedPathMember::String(key)) =
+                (&mut current.value, &member.unspanned)
+            {
+                if !dict.entries.contains_key(key) {
+                    let row = UntaggedValue::Row(Dictionary::default()).into_value(&value.tag);
+                    let next = front.get(idx + 1).unwrap_or(last);
+
+                    let created = match &next.unspanned {
+                        UnspannedPathMember::Int(n) if n.is_zero() => {
+                            UntaggedValue::Table(vec![row]).into_value(&value.tag)
+                        }
+                        _ => row,
+                    };
+
+                    dict.insert_data_at_key(key, created);
+                }
+            }
+
             current = get_mut_data_by_member(current, &member).ok_or_else(|| {
                 ShellError::missing_property(
                     member.plain_string(std::usize::MAX).spanned(member.span),
@@ -407,21 +474,7 @@ pub fn as_string(value: &Value) -> Result<String, ShellError> {
         UntaggedValue::Primitive(Primitive::Int(x)) => Ok(format!("{}", x)),
         UntaggedValue::Primitive(Primitive::Bytes(x)) => Ok(format!("{}", x)),
         UntaggedValue::Primitive(Primitive::Path(x)) => Ok(format!("{}", x.display())),
-        UntaggedValue::Primitive(Primitive::ColumnPath(path)) => {
-            let joined = path
-                .iter()
-                .map(|member| match &member.unspanned {
-                    UnspannedPathMember::String(name) => name.to_string(),
-                    UnspannedPathMember::Int(n) => format!("{}", n),
-                })
-                .join(".");
-
-            if joined.contains(' ') {
-                Ok(format!("\"{}\"", joined))
-            } else {
-                Ok(joined)
-            }
-        }
+        UntaggedValue::Primitive(Primitive::ColumnPath(path)) => Ok(path.as_string()),
 
         // TODO: this should definitely be more general with better errors
         other => Err(ShellError::labeled_error(
@@ -437,15 +490,19 @@ fn insert_data_at_index(
     index: Tagged<usize>,
     new_value: Value,
 ) -> Result<(), ShellError> {
-    if list.len() >= index.item {
+    // An index one past the end appends the value, anything further out is an error
+    if index.item < list.len() {
+        list[index.item] = new_value;
+        Ok(())
+    } else if index.item == list.len() {
+        list.push(new_value);
+        Ok(())
+    } else {
         Err(ShellError::range_error(
-            0..(list.len()),
+            0..(list.len() + 1),
             &format_args!("{}", index.item).spanned(index.tag.span),
             "insert at index",
         ))
-    } else {
-        list[index.item] = new_value;
-        Ok(())
     }
 }
 
@@ -317,6 +317,8 @@ pub fn create_default_context(
             whole_stream_command(Compact),
             whole_stream_command(Default),
             whole_stream_command(SkipWhile),
+            whole_stream_command(TakeWhile),
+            whole_stream_command(TakeUntil),
             whole_stream_command(Range),
             whole_stream_command(Uniq),
             // Table manipulation
@@ -83,6 +83,8 @@ pub(crate) mod split_row;
 #[allow(unused)]
 pub(crate) mod t_sort_by;
 pub(crate) mod table;
+pub(crate) mod take_until;
+pub(crate) mod take_while;
 pub(crate) mod tags;
 pub(crate) mod to_bson;
 pub(crate) mod to_csv;
@@ -186,6 +188,8 @@ pub(crate) use split_row::SplitRow;
 #[allow(unused_imports)]
 pub(crate) use t_sort_by::TSortBy;
 pub(crate) use table::Table;
+pub(crate) use take_until::TakeUntil;
+pub(crate) use take_while::TakeWhile;
 pub(crate) use tags::Tags;
 pub(crate) use to_bson::ToBSON;
 pub(crate) use to_csv::ToCSV;
@@ -1,13 +1,22 @@
 use crate::commands::WholeStreamCommand;
 use crate::prelude::*;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
 use nu_errors::ShellError;
 use nu_protocol::{Primitive, ReturnSuccess, Signature, TaggedDictBuilder, UntaggedValue, Value};
-use rusqlite::{types::ValueRef, Connection, Row, NO_PARAMS};
+use rusqlite::{ffi, types::ValueRef, Connection, Row, NO_PARAMS};
 use std::io::Write;
 use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 pub struct FromSQLite;
 
+#[derive(Deserialize)]
+pub struct FromSQLiteArgs {
+    #[serde(rename(deserialize = "coerce-by-affinity"))]
+    coerce_by_affinity: bool,
+    schema: bool,
+}
+
 impl WholeStreamCommand for FromSQLite {
     fn name(&self) -> &str {
         "from-sqlite"
@@ -15,6 +24,16 @@ impl WholeStreamCommand for FromSQLite {
 
     fn signature(&self) -> Signature {
         Signature::build("from-sqlite")
+            .switch(
+                "coerce-by-affinity",
+                "convert values toward the declared type affinity of their column",
+                Some('c'),
+            )
+            .switch(
+                "schema",
+                "describe each table's columns instead of reading its rows",
+                Some('s'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -26,7 +45,7 @@ impl WholeStreamCommand for FromSQLite {
         args: CommandArgs,
         registry: &CommandRegistry,
     ) -> Result<OutputStream, ShellError> {
-        from_sqlite(args, registry)
+        args.process(registry, from_sqlite)?.run()
     }
 }
 
@@ -39,6 +58,16 @@ impl WholeStreamCommand for FromDB {
 
     fn signature(&self) -> Signature {
         Signature::build("from-db")
+            .switch(
+                "coerce-by-affinity",
+                "convert values toward the declared type affinity of their column",
+                Some('c'),
+            )
+            .switch(
+                "schema",
+                "describe each table's columns instead of reading its rows",
+                Some('s'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -50,13 +79,77 @@ impl WholeStreamCommand for FromDB {
         args: CommandArgs,
         registry: &CommandRegistry,
     ) -> Result<OutputStream, ShellError> {
-        from_sqlite(args, registry)
+        args.process(registry, from_sqlite)?.run()
     }
 }
 
+/// The type affinity SQLite assigns a column based on its declared type
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Affinity {
+    Integer,
+    Text,
+    Blob,
+    Real,
+    Numeric,
+}
+
+impl Affinity {
+    /// Follows the rules from https://www.sqlite.org/datatype3.html#determination_of_column_affinity
+    fn from_declared_type(declared_type: &str) -> Affinity {
+        let declared_type = declared_type.to_uppercase();
+
+        if declared_type.contains("INT") {
+            Affinity::Integer
+        } else if ["CHAR", "CLOB", "TEXT"]
+            .iter()
+            .any(|t| declared_type.contains(t))
+        {
+            Affinity::Text
+        } else if declared_type.is_empty() || declared_type.contains("BLOB") {
+            Affinity::Blob
+        } else if ["REAL", "FLOA", "DOUB"]
+            .iter()
+            .any(|t| declared_type.contains(t))
+        {
+            Affinity::Real
+        } else {
+            Affinity::Numeric
+        }
+    }
+}
+
+/// A column as described by `pragma table_info`
+struct ColumnInfo {
+    name: String,
+    declared_type: String,
+    not_null: bool,
+    primary_key: bool,
+}
+
+fn table_columns(conn: &Connection, table_name: &str) -> Result<Vec<ColumnInfo>, rusqlite::Error> {
+    let mut stmt = conn.prepare(&format!("pragma table_info([{}])", table_name))?;
+    let mut rows = stmt.query(NO_PARAMS)?;
+    let mut columns = vec![];
+
+    while let Some(row) = rows.next()? {
+        let primary_key: i64 = row.get(5)?;
+
+        columns.push(ColumnInfo {
+            name: row.get(1)?,
+            declared_type: row.get(2)?,
+            not_null: row.get(3)?,
+            primary_key: primary_key > 0,
+        });
+    }
+
+    Ok(columns)
+}
+
 pub fn convert_sqlite_file_to_nu_value(
     path: &Path,
     tag: impl Into<Tag> + Clone,
+    coerce_by_affinity: bool,
+    ctrl_c: &AtomicBool,
 ) -> Result<Value, rusqlite::Error> {
     let conn = Connection::open(path)?;
 
@@ -64,13 +157,38 @@ pub fn convert_sqlite_file_to_nu_value(
     let mut meta_stmt = conn.prepare("select name from sqlite_master where type='table'")?;
     let mut meta_rows = meta_stmt.query(NO_PARAMS)?;
     while let Some(meta_row) = meta_rows.next()? {
+        check_interrupted(ctrl_c)?;
         let table_name: String = meta_row.get(0)?;
         let mut meta_dict = TaggedDictBuilder::new(tag.clone());
+        let declared_types: Vec<String> = table_columns(&conn, &table_name)?
+            .into_iter()
+            .map(|column| column.declared_type)
+            .collect();
+        let affinities: Vec<Affinity> = if coerce_by_affinity {
+            declared_types
+                .iter()
+                .map(|declared_type| Affinity::from_declared_type(declared_type))
+                .collect()
+        } else {
+            vec![]
+        };
         let mut out = Vec::new();
         let mut table_stmt = conn.prepare(&format!("select * from [{}]", table_name))?;
+        let column_names: Vec<String> = table_stmt
+            .column_names()
+            .iter()
+            .map(|name| name.to_string())
+            .collect();
         let mut table_rows = table_stmt.query(NO_PARAMS)?;
         while let Some(table_row) = table_rows.next()? {
-            out.push(convert_sqlite_row_to_nu_value(table_row, tag.clone())?)
+            check_interrupted(ctrl_c)?;
+            out.push(convert_sqlite_row_to_nu_value(
+                table_row,
+                &column_names,
+                tag.clone(),
+                &declared_types,
+                &affinities,
+            )?)
         }
         meta_dict.insert_value(
             "table_name".to_string(),
@@ -86,20 +204,216 @@ pub fn convert_sqlite_file_to_nu_value(
     Ok(UntaggedValue::Table(meta_out).into_value(tag))
 }
 
+pub fn convert_sqlite_file_to_schema(
+    path: &Path,
+    tag: impl Into<Tag> + Clone,
+    ctrl_c: &AtomicBool,
+) -> Result<Value, rusqlite::Error> {
+    let conn = Connection::open(path)?;
+
+    let mut meta_out = Vec::new();
+    let mut meta_stmt = conn.prepare("select name from sqlite_master where type='table'")?;
+    let mut meta_rows = meta_stmt.query(NO_PARAMS)?;
+    while let Some(meta_row) = meta_rows.next()? {
+        check_interrupted(ctrl_c)?;
+        let table_name: String = meta_row.get(0)?;
+        let mut meta_dict = TaggedDictBuilder::new(tag.clone());
+        let mut columns = Vec::new();
+        for info in table_columns(&conn, &table_name)? {
+            let mut column = TaggedDictBuilder::new(tag.clone());
+            column.insert_untagged("name", UntaggedValue::string(info.name));
+            column.insert_untagged("type", UntaggedValue::string(info.declared_type));
+            column.insert_untagged("nullable", UntaggedValue::boolean(!info.not_null));
+            column.insert_untagged("primary_key", UntaggedValue::boolean(info.primary_key));
+            columns.push(column.into_value());
+        }
+        meta_dict.insert_value(
+            "table_name".to_string(),
+            UntaggedValue::Primitive(Primitive::String(table_name)).into_value(tag.clone()),
+        );
+        meta_dict.insert_value(
+            "columns",
+            UntaggedValue::Table(columns).into_value(tag.clone()),
+        );
+        meta_out.push(meta_dict.into_value());
+    }
+    let tag = tag.into();
+    Ok(UntaggedValue::Table(meta_out).into_value(tag))
+}
+
+fn check_interrupted(ctrl_c: &AtomicBool) -> Result<(), rusqlite::Error> {
+    if ctrl_c.load(Ordering::SeqCst) {
+        Err(rusqlite::Error::SqliteFailure(
+            ffi::Error::new(ffi::SQLITE_INTERRUPT),
+            None,
+        ))
+    } else {
+        Ok(())
+    }
+}
+
 fn convert_sqlite_row_to_nu_value(
     row: &Row,
+    column_names: &[String],
     tag: impl Into<Tag> + Clone,
+    declared_types: &[String],
+    affinities: &[Affinity],
 ) -> Result<Value, rusqlite::Error> {
     let mut collected = TaggedDictBuilder::new(tag.clone());
-    for (i, c) in row.columns().iter().enumerate() {
-        collected.insert_value(
-            c.name().to_string(),
-            convert_sqlite_value_to_nu_value(row.get_raw(i), tag.clone()),
-        );
+    for (i, name) in column_names.iter().enumerate() {
+        let date = match (declared_types.get(i), row.get_raw(i)) {
+            (Some(declared_type), ValueRef::Text(text)) if is_date_type(declared_type) => {
+                parse_sqlite_date(text)
+            }
+            _ => None,
+        };
+
+        let value = match (date, affinities.get(i)) {
+            (Some(date), _) => {
+                UntaggedValue::Primitive(Primitive::Date(date)).into_value(tag.clone())
+            }
+            (None, Some(affinity)) => {
+                convert_sqlite_value_by_affinity(row.get_raw(i), *affinity, tag.clone())
+            }
+            (None, None) => convert_sqlite_value_to_nu_value(row.get_raw(i), tag.clone()),
+        };
+
+        collected.insert_value(name.clone(), value);
     }
     Ok(collected.into_value())
 }
 
+fn is_date_type(declared_type: &str) -> bool {
+    let declared_type = declared_type.to_uppercase();
+    declared_type.contains("DATE") || declared_type.contains("TIMESTAMP")
+}
+
+/// Parses the text formats SQLite's own date and time functions produce. Times without an offset
+/// are taken to be UTC, as SQLite does.
+fn parse_sqlite_date(text: &[u8]) -> Option<DateTime<Utc>> {
+    let text = std::str::from_utf8(text).ok()?.trim();
+
+    if let Ok(date) = DateTime::parse_from_rfc3339(text) {
+        return Some(date.with_timezone(&Utc));
+    }
+
+    let datetime_formats = [
+        "%Y-%m-%d %H:%M:%S%.f",
+        "%Y-%m-%dT%H:%M:%S%.f",
+        "%Y-%m-%d %H:%M",
+        "%Y-%m-%dT%H:%M",
+    ];
+
+    for format in datetime_formats.iter() {
+        if let Ok(naive) = NaiveDateTime::parse_from_str(text, format) {
+            return Some(DateTime::<Utc>::from_utc(naive, Utc));
+        }
+    }
+
+    NaiveDate::parse_from_str(text, "%Y-%m-%d")
+        .ok()
+        .map(|date| DateTime::<Utc>::from_utc(date.and_hms(0, 0, 0), Utc))
+}
+
+/// Converts a value the way SQLite would have stored it had the column's affinity been applied strictly,
+/// falling back to the stored value when it can't be represented in the column's type.
+fn convert_sqlite_value_by_affinity(
+    value: ValueRef,
+    affinity: Affinity,
+    tag: impl Into<Tag> + Clone,
+) -> Value {
+    let text = match value {
+        ValueRef::Text(s) => std::str::from_utf8(s).ok().map(str::trim),
+        _ => None,
+    };
+
+    // Text such as "NaN" or "inf" parses as a float but isn't a number SQLite would store, so it stays text
+    let number = text
+        .and_then(|t| t.parse::<f64>().ok())
+        .filter(|f| f.is_finite());
+
+    match (affinity, value) {
+        (Affinity::Integer, ValueRef::Text(_)) | (Affinity::Numeric, ValueRef::Text(_)) => {
+            if let Some(i) = text.and_then(|t| t.parse::<i64>().ok()) {
+                return UntaggedValue::int(i).into_value(tag);
+            }
+
+            if let Some(f) = number {
+                return integral_value(f)
+                    .unwrap_or_else(|| UntaggedValue::decimal(f))
+                    .into_value(tag);
+            }
+        }
+        (Affinity::Integer, ValueRef::Real(f)) | (Affinity::Numeric, ValueRef::Real(f)) => {
+            if let Some(i) = integral_value(f) {
+                return i.into_value(tag);
+            }
+        }
+        (Affinity::Real, ValueRef::Integer(i)) => {
+            return UntaggedValue::decimal(i as f64).into_value(tag);
+        }
+        (Affinity::Real, ValueRef::Text(_)) => {
+            if let Some(f) = number {
+                return UntaggedValue::decimal(f).into_value(tag);
+            }
+        }
+        (Affinity::Text, ValueRef::Integer(i)) => {
+            return UntaggedValue::string(i.to_string()).into_value(tag);
+        }
+        (Affinity::Text, ValueRef::Real(f)) => {
+            return UntaggedValue::string(sqlite_real_to_text(f)).into_value(tag);
+        }
+        _ => {}
+    }
+
+    convert_sqlite_value_to_nu_value(value, tag)
+}
+
+/// A real that converts to an integer without loss becomes one, as it would under INTEGER or NUMERIC affinity.
+/// `i64::MAX as f64` rounds up to 2^63, which is already out of range, hence the strict upper bound.
+fn integral_value(f: f64) -> Option<UntaggedValue> {
+    if f.is_finite() && f.fract() == 0.0 && f >= std::i64::MIN as f64 && f < std::i64::MAX as f64 {
+        Some(UntaggedValue::int(f as i64))
+    } else {
+        None
+    }
+}
+
+/// Renders a real the way SQLite does when converting it to text, which is printf's `%!.15g`,
+/// eg) 3.0, 0.3 rather than 0.30000000000000004, and 1.0e+20
+fn sqlite_real_to_text(f: f64) -> String {
+    let scientific = format!("{:.14e}", f);
+    let mut parts = scientific.splitn(2, 'e');
+    let mantissa = parts.next().unwrap_or("");
+    let exponent: i32 = parts.next().and_then(|e| e.parse().ok()).unwrap_or(0);
+
+    if exponent < -4 || exponent >= 15 {
+        format!(
+            "{}e{}{:02}",
+            trim_fraction(mantissa),
+            if exponent < 0 { '-' } else { '+' },
+            exponent.abs()
+        )
+    } else {
+        trim_fraction(&format!("{:.*}", (14 - exponent) as usize, f))
+    }
+}
+
+/// Drops trailing zeros from the fraction while keeping at least one digit after the decimal point
+fn trim_fraction(number: &str) -> String {
+    if !number.contains('.') {
+        return number.to_string();
+    }
+
+    let trimmed = number.trim_end_matches('0');
+
+    if trimmed.ends_with('.') {
+        format!("{}0", trimmed)
+    } else {
+        trimmed.to_string()
+    }
+}
+
 fn convert_sqlite_value_to_nu_value(value: ValueRef, tag: impl Into<Tag> + Clone) -> Value {
     match value {
         ValueRef::Null => {
@@ -107,11 +421,11 @@ fn convert_sqlite_value_to_nu_value(value: ValueRef, tag: impl Into<Tag> + Clone
         }
         ValueRef::Integer(i) => UntaggedValue::int(i).into_value(tag),
         ValueRef::Real(f) => UntaggedValue::decimal(f).into_value(tag),
-        ValueRef::Text(s) => {
-            // this unwrap is safe because we know the ValueRef is Text.
-            UntaggedValue::Primitive(Primitive::String(String::from_utf8_lossy(s).to_string()))
-                .into_value(tag)
-        }
+        ValueRef::Text(s) => match std::str::from_utf8(s) {
+            Ok(s) => UntaggedValue::Primitive(Primitive::String(s.to_string())).into_value(tag),
+            // Legacy databases can store other encodings in TEXT columns, so keep the bytes as they are
+            Err(_) => UntaggedValue::binary(s.to_owned()).into_value(tag),
+        },
         ValueRef::Blob(u) => UntaggedValue::binary(u.to_owned()).into_value(tag),
     }
 }
@@ -119,6 +433,9 @@ fn convert_sqlite_value_to_nu_value(value: ValueRef, tag: impl Into<Tag> + Clone
 pub fn from_sqlite_bytes_to_value(
     mut bytes: Vec<u8>,
     tag: impl Into<Tag> + Clone,
+    coerce_by_affinity: bool,
+    schema: bool,
+    ctrl_c: &AtomicBool,
 ) -> Result<Value, std::io::Error> {
     // FIXME: should probably write a sqlite virtual filesystem
     // that will allow us to use bytes as a file to avoid this
@@ -126,16 +443,38 @@ pub fn from_sqlite_bytes_to_value(
     // best done as a PR to rusqlite.
     let mut tempfile = tempfile::NamedTempFile::new()?;
     tempfile.write_all(bytes.as_mut_slice())?;
-    match convert_sqlite_file_to_nu_value(tempfile.path(), tag) {
+    let value = if schema {
+        convert_sqlite_file_to_schema(tempfile.path(), tag, ctrl_c)
+    } else {
+        convert_sqlite_file_to_nu_value(tempfile.path(), tag, coerce_by_affinity, ctrl_c)
+    };
+    match value {
         Ok(value) => Ok(value),
         Err(e) => Err(std::io::Error::new(std::io::ErrorKind::Other, e)),
     }
 }
 
-fn from_sqlite(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
-    let args = args.evaluate_once(registry)?;
-    let tag = args.name_tag();
-    let input = args.input;
+fn from_sqlite(
+    FromSQLiteArgs {
+        coerce_by_affinity,
+        schema,
+    }: FromSQLiteArgs,
+    RunnableContext {
+        input,
+        name,
+        ctrl_c,
+        ..
+    }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    if coerce_by_affinity && schema {
+        return Err(ShellError::labeled_error(
+            "--coerce-by-affinity can't be combined with --schema",
+            "--schema describes the columns without reading any values",
+            &name,
+        ));
+    }
+
+    let tag = name;
 
     let stream = async_stream! {
         let values: Vec<Value> = input.values.collect().await;
@@ -144,7 +483,7 @@ fn from_sqlite(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputSt
             let value_tag = &value.tag;
             match value.value {
                 UntaggedValue::Primitive(Primitive::Binary(vb)) =>
-                    match from_sqlite_bytes_to_value(vb, tag.clone()) {
+                    match from_sqlite_bytes_to_value(vb, tag.clone(), coerce_by_affinity, schema, &ctrl_c) {
                         Ok(x) => match x {
                             Value { value: UntaggedValue::Table(list), .. } => {
                                 for l in list {
@@ -153,6 +492,7 @@ fn from_sqlite(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputSt
                             }
                             _ => yield ReturnSuccess::value(x),
                         }
+                        Err(_) if ctrl_c.load(Ordering::SeqCst) => break,
                         Err(err) => {
                             println!("{:?}", err);
                             yield Err(ShellError::labeled_error_with_secondary(
@@ -6,17 +6,55 @@ use indexmap::set::IndexSet;
 use log::trace;
 use nu_errors::ShellError;
 use nu_protocol::{
-    did_you_mean, ColumnPath, PathMember, ReturnSuccess, ReturnValue, Signature, SyntaxShape,
-    UnspannedPathMember, UntaggedValue, Value,
+    did_you_mean, ColumnPath, PathMember, Primitive, ReturnSuccess, ReturnValue, Signature,
+    SyntaxShape, TaggedDictBuilder, UnspannedPathMember, UntaggedValue, Value,
 };
-use nu_source::span_for_spanned_list;
-use nu_value_ext::get_data_by_column_path;
+use nu_source::{span_for_spanned_list, Tagged};
+use nu_value_ext::{get_data_by_column_path, get_data_by_member};
+use regex::Regex;
 
 pub struct Get;
 
 #[derive(Deserialize)]
 pub struct GetArgs {
     rest: Vec<ColumnPath>,
+    #[serde(rename(deserialize = "match"))]
+    pattern: Option<Tagged<String>>,
+    strict: bool,
+    #[serde(rename(deserialize = "show-path"))]
+    show_path: bool,
+    #[serde(rename(deserialize = "deep-search"))]
+    deep_search: Option<Tagged<String>>,
+    leaves: bool,
+    #[serde(rename(deserialize = "on-type-mismatch"))]
+    on_type_mismatch: Option<Tagged<String>>,
+    default: Option<Value>,
+}
+
+/// What to do when a column path reaches a value that can't be indexed by the next member
+#[derive(Clone, Copy)]
+enum OnTypeMismatch {
+    Error,
+    Nothing,
+    Coerce,
+}
+
+impl OnTypeMismatch {
+    fn from_arg(arg: Option<Tagged<String>>) -> Result<OnTypeMismatch, ShellError> {
+        match arg {
+            None => Ok(OnTypeMismatch::Error),
+            Some(arg) => match arg.item.as_str() {
+                "error" => Ok(OnTypeMismatch::Error),
+                "nothing" => Ok(OnTypeMismatch::Nothing),
+                "coerce" => Ok(OnTypeMismatch::Coerce),
+                _ => Err(ShellError::labeled_error(
+                    "Unknown --on-type-mismatch option",
+                    "expected one of: error, nothing, coerce",
+                    &arg.tag,
+                )),
+            },
+        }
+    }
 }
 
 impl WholeStreamCommand for Get {
@@ -25,10 +63,50 @@ impl WholeStreamCommand for Get {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("get").rest(
-            SyntaxShape::ColumnPath,
-            "optionally return additional data by path",
-        )
+        Signature::build("get")
+            .named(
+                "match",
+                SyntaxShape::String,
+                "a regex whose first capture group is extracted from each string",
+                Some('m'),
+            )
+            .switch(
+                "strict",
+                "error on strings the --match pattern doesn't match instead of returning nothing",
+                None,
+            )
+            .switch(
+                "show-path",
+                "show how each column path was interpreted instead of fetching data",
+                None,
+            )
+            .named(
+                "deep-search",
+                SyntaxShape::String,
+                "find every column with this name, however deeply nested, along with its path",
+                None,
+            )
+            .named(
+                "on-type-mismatch",
+                SyntaxShape::String,
+                "what to do when a path runs into a plain value: error (default), nothing, or coerce",
+                None,
+            )
+            .named(
+                "default",
+                SyntaxShape::Any,
+                "a value to return when a path can't be found",
+                Some('d'),
+            )
+            .switch(
+                "leaves",
+                "list the path and value of every cell that doesn't contain rows or tables",
+                None,
+            )
+            .rest(
+                SyntaxShape::ColumnPath,
+                "optionally return additional data by path",
+            )
     }
 
     fn usage(&self) -> &str {
@@ -178,10 +256,159 @@ pub fn get_column_path(path: &ColumnPath, obj: &Value) -> Result<Value, ShellErr
     )
 }
 
+fn get_column_path_on_mismatch(
+    path: &ColumnPath,
+    obj: &Value,
+    on_type_mismatch: OnTypeMismatch,
+) -> Result<Value, ShellError> {
+    if let OnTypeMismatch::Error = on_type_mismatch {
+        return get_column_path(path, obj);
+    }
+
+    let mut current = obj.clone();
+
+    for member in path.iter() {
+        match get_data_by_member(&current, member) {
+            Ok(next) => current = next,
+            Err(_) if is_container(&current) => return get_column_path(path, obj),
+            Err(reason) => match on_type_mismatch {
+                OnTypeMismatch::Nothing => {
+                    return Ok(UntaggedValue::nothing().into_value(&current.tag))
+                }
+                _ => current = coerce_member(&current, member).ok_or(reason)?,
+            },
+        }
+    }
+
+    Ok(current)
+}
+
+fn is_container(value: &Value) -> bool {
+    match value.value {
+        UntaggedValue::Row(_) | UntaggedValue::Table(_) => true,
+        _ => false,
+    }
+}
+
+/// Best-effort indexing into a plain value: a string is treated as a list of its characters
+/// and any other value as a list holding just itself
+fn coerce_member(value: &Value, member: &PathMember) -> Option<Value> {
+    let idx = match &member.unspanned {
+        UnspannedPathMember::Int(idx) => idx.to_usize()?,
+        UnspannedPathMember::String(_) => return None,
+    };
+
+    match &value.value {
+        UntaggedValue::Primitive(Primitive::String(s)) => s
+            .chars()
+            .nth(idx)
+            .map(|c| UntaggedValue::string(c.to_string()).into_value(&value.tag)),
+        _ if idx == 0 => Some(value.clone()),
+        _ => None,
+    }
+}
+
 pub fn get(
-    GetArgs { rest: mut fields }: GetArgs,
-    RunnableContext { input, .. }: RunnableContext,
+    GetArgs {
+        rest: mut fields,
+        pattern,
+        strict,
+        show_path,
+        deep_search,
+        leaves,
+        on_type_mismatch,
+        default,
+    }: GetArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
 ) -> Result<OutputStream, ShellError> {
+    if strict && pattern.is_none() {
+        return Err(ShellError::labeled_error(
+            "--strict can only be used with --match",
+            "requires --match",
+            &name,
+        ));
+    }
+
+    let modes: Vec<&str> = vec![
+        ("--show-path", show_path),
+        ("--match", pattern.is_some()),
+        ("--deep-search", deep_search.is_some()),
+        ("--leaves", leaves),
+    ]
+    .into_iter()
+    .filter(|(_, given)| *given)
+    .map(|(flag, _)| flag)
+    .collect();
+
+    if modes.len() > 1 {
+        return Err(ShellError::labeled_error(
+            format!("{} can't be combined", modes.join(" and ")),
+            "use only one of --show-path, --match, --deep-search or --leaves",
+            &name,
+        ));
+    }
+
+    // --default and --on-type-mismatch only apply when following column paths
+    if !modes.is_empty() || fields.is_empty() {
+        if let Some(default) = &default {
+            return Err(ShellError::labeled_error(
+                "--default only applies to column paths",
+                "requires a column path to fall back from",
+                &default.tag,
+            ));
+        }
+
+        if let Some(arg) = &on_type_mismatch {
+            return Err(ShellError::labeled_error(
+                "--on-type-mismatch only applies to column paths",
+                "requires a column path to follow",
+                &arg.tag,
+            ));
+        }
+    }
+
+    let on_type_mismatch = OnTypeMismatch::from_arg(on_type_mismatch)?;
+
+    if show_path {
+        return Ok(show_column_paths(&fields, &name));
+    }
+
+    if let Some(pattern) = pattern {
+        if !fields.is_empty() {
+            return Err(ShellError::labeled_error(
+                "--match can't be combined with column paths",
+                "try `get <path> | get --match <pattern>` instead",
+                &pattern.tag,
+            ));
+        }
+
+        return get_match(&pattern, strict, input);
+    }
+
+    if let Some(key) = deep_search {
+        if !fields.is_empty() {
+            return Err(ShellError::labeled_error(
+                "--deep-search can't be combined with column paths",
+                "try `get <path> | get --deep-search <name>` instead",
+                &key.tag,
+            ));
+        }
+
+        return Ok(get_deep_search(key.item, input));
+    }
+
+    if leaves {
+        if !fields.is_empty() {
+            return Err(ShellError::labeled_error(
+                "--leaves can't be combined with column paths",
+                "try `get <path> | get --leaves` instead",
+                &name,
+            ));
+        }
+
+        return Ok(get_leaves(input));
+    }
+
     if fields.is_empty() {
         let stream = async_stream! {
             let values = input.values;
@@ -219,7 +446,7 @@ pub fn get(
                     .collect::<Vec<&ColumnPath>>();
 
                 for path in column_paths {
-                    let res = get_column_path(&path, &item);
+                    let res = get_column_path_on_mismatch(&path, &item, on_type_mismatch);
 
                     match res {
                         Ok(got) => match got {
@@ -233,9 +460,14 @@ pub fn get(
                             }
                             other => result.push_back(ReturnSuccess::value(other.clone())),
                         },
-                        Err(reason) => result.push_back(ReturnSuccess::value(
-                            UntaggedValue::Error(reason).into_untagged_value(),
-                        )),
+                        Err(reason) => match &default {
+                            Some(default) => result.push_back(ReturnSuccess::value(
+                                default.value.clone().into_value(&item.tag),
+                            )),
+                            None => result.push_back(ReturnSuccess::value(
+                                UntaggedValue::Error(reason).into_untagged_value(),
+                            )),
+                        },
                     }
                 }
 
@@ -246,3 +478,172 @@ pub fn get(
         Ok(stream.to_output_stream())
     }
 }
+
+fn show_column_paths(fields: &[ColumnPath], tag: &Tag) -> OutputStream {
+    let rows: VecDeque<ReturnValue> = fields
+        .iter()
+        .map(|path| {
+            let members = path
+                .iter()
+                .map(|member| {
+                    let kind = match member.unspanned {
+                        UnspannedPathMember::String(_) => "string",
+                        UnspannedPathMember::Int(_) => "int",
+                    };
+
+                    UntaggedValue::string(kind).into_value(member.span)
+                })
+                .collect();
+
+            let mut row = TaggedDictBuilder::new(tag);
+            row.insert_untagged("path", UntaggedValue::string(path.as_string()));
+            row.insert_untagged("members", UntaggedValue::Table(members));
+
+            ReturnSuccess::value(row.into_value())
+        })
+        .collect();
+
+    rows.into()
+}
+
+fn get_deep_search(key: String, input: InputStream) -> OutputStream {
+    input
+        .values
+        .map(move |item| {
+            let mut found = VecDeque::new();
+            collect_deep_matches(&item, &key, &mut vec![], &mut found);
+            futures::stream::iter(found)
+        })
+        .flatten()
+        .to_output_stream()
+}
+
+fn collect_deep_matches(
+    value: &Value,
+    key: &str,
+    path: &mut Vec<PathMember>,
+    found: &mut VecDeque<ReturnValue>,
+) {
+    match &value.value {
+        UntaggedValue::Row(dict) => {
+            for (name, column) in dict.entries.iter() {
+                path.push(PathMember::string(name, column.tag.span));
+
+                if name == key {
+                    let mut row = TaggedDictBuilder::new(&column.tag);
+                    row.insert_untagged(
+                        "path",
+                        UntaggedValue::string(ColumnPath::new(path.clone()).as_string()),
+                    );
+                    row.insert_value("value", column.clone());
+                    found.push_back(ReturnSuccess::value(row.into_value()));
+                }
+
+                collect_deep_matches(column, key, path, found);
+                path.pop();
+            }
+        }
+        UntaggedValue::Table(rows) => {
+            for (idx, row) in rows.iter().enumerate() {
+                path.push(PathMember::int(idx, row.tag.span));
+                collect_deep_matches(row, key, path, found);
+                path.pop();
+            }
+        }
+        _ => {}
+    }
+}
+
+fn get_leaves(input: InputStream) -> OutputStream {
+    input
+        .values
+        .map(move |item| {
+            let mut found = VecDeque::new();
+            collect_leaves(&item, &mut vec![], &mut found);
+            futures::stream::iter(found)
+        })
+        .flatten()
+        .to_output_stream()
+}
+
+fn collect_leaves(value: &Value, path: &mut Vec<PathMember>, found: &mut VecDeque<ReturnValue>) {
+    match &value.value {
+        UntaggedValue::Row(dict) if !dict.entries.is_empty() => {
+            for (name, column) in dict.entries.iter() {
+                path.push(PathMember::string(name, column.tag.span));
+                collect_leaves(column, path, found);
+                path.pop();
+            }
+        }
+        UntaggedValue::Table(rows) if !rows.is_empty() => {
+            for (idx, row) in rows.iter().enumerate() {
+                path.push(PathMember::int(idx, row.tag.span));
+                collect_leaves(row, path, found);
+                path.pop();
+            }
+        }
+        _ => {
+            let mut row = TaggedDictBuilder::new(&value.tag);
+            row.insert_untagged(
+                "path",
+                UntaggedValue::string(ColumnPath::new(path.clone()).as_string()),
+            );
+            row.insert_value("value", value.clone());
+            found.push_back(ReturnSuccess::value(row.into_value()));
+        }
+    }
+}
+
+fn get_match(
+    pattern: &Tagged<String>,
+    strict: bool,
+    input: InputStream,
+) -> Result<OutputStream, ShellError> {
+    let regex = Regex::new(&pattern.item).map_err(|_| {
+        ShellError::labeled_error(
+            "Could not parse regex",
+            "could not parse regex",
+            &pattern.tag,
+        )
+    })?;
+
+    // Patterns without a capture group extract the whole match instead
+    let group = if regex.captures_len() > 1 { 1 } else { 0 };
+
+    let stream = input.values.map(move |item| {
+        // Lines carry their newline along, which would keep end anchors from matching
+        let s = match &item.value {
+            UntaggedValue::Primitive(Primitive::Line(line)) => Ok(line.clone()),
+            _ => item.as_string(),
+        };
+
+        let s = match s {
+            Ok(s) => s,
+            Err(reason) => {
+                return ReturnSuccess::value(UntaggedValue::Error(reason).into_untagged_value())
+            }
+        };
+
+        let extracted = regex
+            .captures(&s)
+            .and_then(|cap| cap.get(group))
+            .map(|m| m.as_str().to_string());
+
+        match extracted {
+            Some(extracted) => {
+                ReturnSuccess::value(UntaggedValue::string(extracted).into_value(&item.tag))
+            }
+            None if strict => ReturnSuccess::value(
+                UntaggedValue::Error(ShellError::labeled_error(
+                    "No match",
+                    "the pattern didn't match this string",
+                    &item.tag,
+                ))
+                .into_untagged_value(),
+            ),
+            None => ReturnSuccess::value(UntaggedValue::nothing().into_value(&item.tag)),
+        }
+    });
+
+    Ok(stream.to_output_stream())
+}
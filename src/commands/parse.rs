@@ -82,11 +82,17 @@ impl PerItemCommand for Parse {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("parse").required(
-            "pattern",
-            SyntaxShape::Any,
-            "the pattern to match. Eg) \"{foo}: {bar}\"",
-        )
+        Signature::build("parse")
+            .required(
+                "pattern",
+                SyntaxShape::Any,
+                "the pattern to match. Eg) \"{foo}: {bar}\"",
+            )
+            .switch(
+                "regex",
+                "use the pattern as a regex, naming columns after its capture groups",
+                Some('r'),
+            )
     }
 
     fn usage(&self) -> &str {
@@ -103,30 +109,51 @@ impl PerItemCommand for Parse {
         //let value_tag = value.tag();
         let pattern = call_info.args.expect_nth(0)?.as_string()?;
 
-        let parse_pattern = parse(&pattern).map_err(|_| {
-            ShellError::labeled_error(
-                "Could not create parse pattern",
-                "could not create parse pattern",
-                &value.tag,
+        let (parse_regex, column_names) = if call_info.args.has("regex") {
+            (pattern, None)
+        } else {
+            let parse_pattern = parse(&pattern).map_err(|_| {
+                ShellError::labeled_error(
+                    "Could not create parse pattern",
+                    "could not create parse pattern",
+                    &value.tag,
+                )
+            })?;
+
+            (
+                build_regex(&parse_pattern.1),
+                Some(column_names(&parse_pattern.1)),
             )
-        })?;
-        let parse_regex = build_regex(&parse_pattern.1);
+        };
 
-        let column_names = column_names(&parse_pattern.1);
         let regex = Regex::new(&parse_regex).map_err(|_| {
             ShellError::labeled_error("Could not parse regex", "could not parse regex", &value.tag)
         })?;
 
+        // A regex without capture groups gives the whole match instead, as `get --match` does
+        let first_group = if regex.captures_len() > 1 { 1 } else { 0 };
+
+        // Named groups keep their names and unnamed ones are numbered, eg) Capture1
+        let column_names = column_names.unwrap_or_else(|| {
+            regex
+                .capture_names()
+                .skip(first_group)
+                .enumerate()
+                .map(|(idx, name)| match name {
+                    Some(name) => name.to_string(),
+                    None => format!("Capture{}", idx + first_group),
+                })
+                .collect()
+        });
+
         let output = if let Ok(s) = value.as_string() {
             let mut results = vec![];
             for cap in regex.captures_iter(&s) {
                 let mut dict = TaggedDictBuilder::new(value.tag());
 
                 for (idx, column_name) in column_names.iter().enumerate() {
-                    dict.insert_untagged(
-                        column_name,
-                        UntaggedValue::string(&cap[idx + 1].to_string()),
-                    );
+                    let captured = cap.get(idx + first_group).map(|m| m.as_str()).unwrap_or("");
+                    dict.insert_untagged(column_name, UntaggedValue::string(captured));
                 }
 
                 results.push(ReturnSuccess::value(dict.into_value()));
@@ -8,7 +8,7 @@ use nu_protocol::{
     TaggedDictBuilder, UnspannedPathMember, UntaggedValue, Value,
 };
 use nu_source::span_for_spanned_list;
-use nu_value_ext::{as_string, get_data_by_column_path};
+use nu_value_ext::get_data_by_column_path;
 
 #[derive(Deserialize)]
 struct PickArgs {
@@ -88,8 +88,7 @@ fn pick(
                 }));
 
 
-                let field = path.clone();
-                let key = as_string(&UntaggedValue::Primitive(Primitive::ColumnPath(field.clone())).into_untagged_value())?;
+                let key = column_key(path);
 
                 match fetcher {
                     Ok(results) => {
@@ -165,3 +164,14 @@ fn pick(
 
     Ok(stream.to_output_stream())
 }
+
+// The picked column is named after the path members as they are, without the quoting used when displaying paths
+fn column_key(path: &ColumnPath) -> String {
+    path.iter()
+        .map(|member| match &member.unspanned {
+            UnspannedPathMember::String(name) => name.to_string(),
+            UnspannedPathMember::Int(n) => format!("{}", n),
+        })
+        .collect::<Vec<String>>()
+        .join(".")
+}
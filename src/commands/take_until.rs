@@ -0,0 +1,61 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use log::trace;
+use nu_errors::ShellError;
+use nu_protocol::{Evaluate, Scope, Signature, SyntaxShape};
+
+pub struct TakeUntil;
+
+#[derive(Deserialize)]
+pub struct TakeUntilArgs {
+    condition: Evaluate,
+}
+
+impl WholeStreamCommand for TakeUntil {
+    fn name(&self) -> &str {
+        "take-until"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("take-until")
+            .required(
+                "condition",
+                SyntaxShape::Block,
+                "the condition that must be met to stop taking",
+            )
+            .filter()
+    }
+
+    fn usage(&self) -> &str {
+        "Takes rows until the condition matches."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, take_until)?.run()
+    }
+}
+
+pub fn take_until(
+    TakeUntilArgs { condition }: TakeUntilArgs,
+    RunnableContext { input, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let objects = input.values.take_while(move |item| {
+        trace!("ITEM = {:?}", item);
+        let result = condition.invoke(&Scope::new(item.clone()));
+        trace!("RESULT = {:?}", result);
+
+        let return_value = match result {
+            Ok(ref v) if v.is_true() => false,
+            Ok(_) => true,
+            _ => false,
+        };
+
+        futures::future::ready(return_value)
+    });
+
+    Ok(objects.from_input_stream())
+}
@@ -0,0 +1,60 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use log::trace;
+use nu_errors::ShellError;
+use nu_protocol::{Evaluate, Scope, Signature, SyntaxShape};
+
+pub struct TakeWhile;
+
+#[derive(Deserialize)]
+pub struct TakeWhileArgs {
+    condition: Evaluate,
+}
+
+impl WholeStreamCommand for TakeWhile {
+    fn name(&self) -> &str {
+        "take-while"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("take-while")
+            .required(
+                "condition",
+                SyntaxShape::Block,
+                "the condition that must be met to continue taking",
+            )
+            .filter()
+    }
+
+    fn usage(&self) -> &str {
+        "Takes rows while the condition matches."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, take_while)?.run()
+    }
+}
+
+pub fn take_while(
+    TakeWhileArgs { condition }: TakeWhileArgs,
+    RunnableContext { input, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let objects = input.values.take_while(move |item| {
+        trace!("ITEM = {:?}", item);
+        let result = condition.invoke(&Scope::new(item.clone()));
+        trace!("RESULT = {:?}", result);
+
+        let return_value = match result {
+            Ok(ref v) if v.is_true() => true,
+            _ => false,
+        };
+
+        futures::future::ready(return_value)
+    });
+
+    Ok(objects.from_input_stream())
+}
@@ -168,9 +168,7 @@ impl PrettyDebug for FormatInlineShape {
             }
             InlineShape::String(string) => b::primitive(string),
             InlineShape::Line(string) => b::primitive(string),
-            InlineShape::ColumnPath(path) => {
-                b::intersperse(path.iter().map(|member| member.pretty()), b::keyword("."))
-            }
+            InlineShape::ColumnPath(path) => b::primitive(path.as_string()),
             InlineShape::Pattern(pattern) => b::primitive(pattern),
             InlineShape::Boolean(boolean) => b::primitive(
                 match (boolean, column) {
@@ -45,6 +45,16 @@ fn contains(
             UntaggedValue::Primitive(Primitive::Line(l)),
             UntaggedValue::Primitive(Primitive::Line(r)),
         ) => Ok(l.contains(r)),
+        // A list matches when any of its items do
+        (UntaggedValue::Table(values), _) => {
+            for value in values {
+                if contains(&value.value, right)? {
+                    return Ok(true);
+                }
+            }
+
+            Ok(false)
+        }
         _ => Err((left.type_name(), right.type_name())),
     }
 }
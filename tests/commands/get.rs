@@ -1,4 +1,4 @@
-use nu_test_support::fs::Stub::FileWithContent;
+use nu_test_support::fs::Stub::{EmptyFile, FileWithContent};
 use nu_test_support::playground::Playground;
 use nu_test_support::{nu, nu_error, pipeline};
 
@@ -235,3 +235,359 @@ fn errors_fetching_by_index_out_of_bounds() {
         )
     })
 }
+
+#[test]
+fn fetches_date_fields() {
+    Playground::setup("get_test_9", |dirs, sandbox| {
+        sandbox.with_files(vec![EmptyFile("andres.txt")]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                ls
+                | get modified.timezone
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "UTC");
+    })
+}
+
+#[test]
+fn fetches_calendar_fields_of_a_date() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open events.db
+            | get table_values
+            | nth 0
+            | get created_at.year
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "2020");
+
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open events.db
+            | get table_values
+            | nth 0
+            | get created_at.month
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "2");
+
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open events.db
+            | get table_values
+            | nth 0
+            | get created_at.day
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "1");
+
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open events.db
+            | get table_values
+            | nth 0
+            | get created_at.hour
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "10");
+}
+
+#[test]
+fn errors_fetching_unknown_date_field() {
+    Playground::setup("get_test_10", |dirs, sandbox| {
+        sandbox.with_files(vec![EmptyFile("andres.txt")]);
+
+        let actual = nu_error!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                ls
+                | get modified
+                | first
+                | get fortnight
+            "#
+        ));
+
+        assert!(
+            actual.contains("valid date fields are: year, month, day"),
+            format!("actual: {:?}", actual)
+        );
+    })
+}
+
+#[test]
+fn fetches_first_capture_group_of_each_string_with_match() {
+    Playground::setup("get_test_11", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "arepas.txt",
+            r#"
+                order 1: 2 arepas
+                order 2: 24 arepas
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open arepas.txt
+                | lines
+                | get --match ': (\d+)'
+                | nth 1
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "24");
+    })
+}
+
+#[test]
+fn matches_lines_without_their_trailing_newline() {
+    Playground::setup("get_test_16", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "builds.txt",
+            r#"
+                build 12
+                build 345
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open builds.txt
+                | lines
+                | get --match '(\d+)$'
+                | nth 1
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "345");
+    })
+}
+
+#[test]
+fn rejects_strict_without_match() {
+    Playground::setup("get_test_17", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "sample.toml",
+            r#"
+                [package]
+                name = "nu"
+            "#,
+        )]);
+
+        let actual = nu_error!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open sample.toml
+                | get package.name --strict
+            "#
+        ));
+
+        assert!(
+            actual.contains("--strict can only be used with --match"),
+            format!("actual: {:?}", actual)
+        );
+    })
+}
+
+#[test]
+fn rejects_combining_modes() {
+    Playground::setup("get_test_18", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "sample.toml",
+            r#"
+                [package]
+                name = "nu"
+            "#,
+        )]);
+
+        let actual = nu_error!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open sample.toml
+                | get --deep-search name --leaves
+            "#
+        ));
+
+        assert!(
+            actual.contains("--deep-search and --leaves can't be combined"),
+            format!("actual: {:?}", actual)
+        );
+    })
+}
+
+#[test]
+fn rejects_path_options_without_column_paths() {
+    Playground::setup("get_test_19", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "sample.toml",
+            r#"
+                [package]
+                name = "nu"
+            "#,
+        )]);
+
+        let actual = nu_error!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open sample.toml
+                | get --deep-search name --default 30
+            "#
+        ));
+
+        assert!(
+            actual.contains("--default only applies to column paths"),
+            format!("actual: {:?}", actual)
+        );
+
+        let actual = nu_error!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open sample.toml
+                | get --leaves --on-type-mismatch bogus
+            "#
+        ));
+
+        assert!(
+            actual.contains("--on-type-mismatch only applies to column paths"),
+            format!("actual: {:?}", actual)
+        );
+    })
+}
+
+#[test]
+fn shows_how_a_column_path_was_interpreted() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            get --show-path package.authors.2
+            | get members
+            | nth 2
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "int");
+}
+
+#[test]
+fn finds_nested_columns_with_deep_search() {
+    Playground::setup("get_test_12", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "sample.toml",
+            r#"
+                [[fortune_tellers]]
+                name = "Andrés N. Robalino"
+
+                [[fortune_tellers]]
+                name = "Jonathan Turner"
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open sample.toml
+                | get --deep-search name
+                | nth 1
+                | get path
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "fortune_tellers.1.name");
+    })
+}
+
+#[test]
+fn lists_the_path_of_every_leaf() {
+    Playground::setup("get_test_13", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "sample.toml",
+            r#"
+                [package]
+                authors = ["Yehuda Katz", "Jonathan Turner", "Andrés N. Robalino"]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open sample.toml
+                | get --leaves
+                | nth 2
+                | get path
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "package.authors.2");
+    })
+}
+
+#[test]
+fn coerces_plain_values_when_asked_on_type_mismatch() {
+    Playground::setup("get_test_14", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "sample.toml",
+            r#"
+                [package]
+                name = "nu"
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open sample.toml
+                | get package.name.1 --on-type-mismatch coerce
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "u");
+    })
+}
+
+#[test]
+fn returns_the_default_for_missing_paths() {
+    Playground::setup("get_test_15", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "sample.toml",
+            r#"
+                [package]
+                name = "nu"
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open sample.toml
+                | get package.timeout --default 30
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "30");
+    })
+}
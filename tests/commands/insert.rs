@@ -14,3 +14,63 @@ fn insert_plugin() {
 
     assert_eq!(actual, "1");
 }
+
+#[test]
+fn insert_creates_missing_intermediate_rows() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open cargo_sample.toml
+            | insert package.metadata.docs.rs "all-features"
+            | get package.metadata.docs.rs
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "all-features");
+}
+
+#[test]
+fn insert_creates_a_missing_table_for_the_first_row() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open cargo_sample.toml
+            | insert package.servers.0.port 80
+            | get package.servers.0.port
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "80");
+}
+
+#[test]
+fn insert_at_the_first_row_of_a_missing_table() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open cargo_sample.toml
+            | insert package.servers.0 80
+            | get package.servers.0
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "80");
+}
+
+#[test]
+fn insert_appends_one_row_past_the_end() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open cargo_sample.toml
+            | insert package.authors.1 "Andrés N. Robalino"
+            | get package.authors.1
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "Andrés N. Robalino");
+}
@@ -28,6 +28,8 @@ mod save;
 mod sort_by;
 mod split_by;
 mod split_column;
+mod take_until;
+mod take_while;
 mod touch;
 mod uniq;
 mod where_;
@@ -28,3 +28,57 @@ fn extracts_fields_from_the_given_the_pattern() {
         assert_eq!(actual, "JonathanParsed");
     })
 }
+
+#[test]
+fn extracts_named_capture_groups_with_regex() {
+    Playground::setup("parse_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "arepa_orders.txt",
+            r#"
+                2020-01-15 Cheese
+                2020-02-03 JonathanParsed
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open arepa_orders.txt
+                | lines
+                | parse --regex '(?P<year>\d+)-(?P<month>\d+)-(?P<day>\d+) (\w+)'
+                | nth 1
+                | get month
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "02");
+    })
+}
+
+#[test]
+fn extracts_the_whole_match_when_the_regex_has_no_groups() {
+    Playground::setup("parse_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "arepa_orders.txt",
+            r#"
+                2020-01-15 Cheese
+                2020-02-03 JonathanParsed
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open arepa_orders.txt
+                | lines
+                | parse --regex '\d+-\d+'
+                | nth 1
+                | get Capture0
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "2020-02");
+    })
+}
@@ -99,3 +99,40 @@ fn allows_if_given_unknown_column_name_is_missing() {
         assert_eq!(actual, "3");
     })
 }
+
+#[test]
+fn column_names_are_kept_as_given() {
+    Playground::setup("pick_test_4", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "releases.csv",
+            r#"
+                name,2019,Size(MB)
+                nu,0.8,12
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open releases.csv
+                | pick "2019" "Size(MB)"
+                | get "2019"
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "0.8");
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open releases.csv
+                | pick "2019" "Size(MB)"
+                | get "Size(MB)"
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "12");
+    })
+}
@@ -0,0 +1,49 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn takes_rows_until_the_condition_matches() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open cargo_sample.toml -r
+            | lines
+            | take-until $it == "[dependencies]"
+            | count
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "8");
+}
+
+#[test]
+fn takes_every_row_when_the_condition_never_matches() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open cargo_sample.toml -r
+            | lines
+            | take-until $it == "[arepas]"
+            | count
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "53");
+}
+
+#[test]
+fn takes_nothing_from_empty_input() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo ""
+            | lines
+            | take-until $it == "[dependencies]"
+            | count
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "0");
+}
@@ -0,0 +1,17 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn takes_rows_until_the_condition_fails() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open cargo_sample.toml -r
+            | lines
+            | take-while $it != "[dependencies]"
+            | count
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "8");
+}
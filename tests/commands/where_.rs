@@ -1,3 +1,5 @@
+use nu_test_support::fs::Stub::FileWithContent;
+use nu_test_support::playground::Playground;
 use nu_test_support::{nu, pipeline};
 
 #[test]
@@ -118,3 +120,47 @@ fn contains_operator() {
 
     assert_eq!(actual, "2");
 }
+
+#[test]
+fn contains_operator_matches_any_item_of_a_list() {
+    Playground::setup("where_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "sample.toml",
+            r#"
+                [[packages]]
+                name = "nu"
+                authors = ["Yehuda Katz", "Jonathan Turner"]
+
+                [[packages]]
+                name = "arepas"
+                authors = ["Andrés N. Robalino"]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open sample.toml
+                | get packages
+                | where authors =~ Turner
+                | get name
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "nu");
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open sample.toml
+                | get packages
+                | where authors !~ Turner
+                | get name
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "arepas");
+    })
+}
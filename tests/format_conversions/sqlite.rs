@@ -1,4 +1,4 @@
-use nu_test_support::{nu, pipeline};
+use nu_test_support::{nu, nu_error, pipeline};
 
 #[test]
 fn table_to_sqlite_and_back_into_table() {
@@ -17,3 +17,145 @@ fn table_to_sqlite_and_back_into_table() {
 
     assert_eq!(actual, "hello");
 }
+
+#[test]
+fn reads_every_row_of_each_table_with_its_own_columns() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open sample.db
+            | get table_values
+            | nth 7
+            | get z
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "42");
+}
+
+#[test]
+fn describes_the_columns_of_each_table() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open sample.db --raw
+            | from-sqlite --schema
+            | get columns
+            | nth 1
+            | get type
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "blob");
+}
+
+#[test]
+fn rejects_coercing_values_when_describing_the_schema() {
+    let actual = nu_error!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open sample.db --raw
+            | from-sqlite --schema --coerce-by-affinity
+        "#
+    ));
+
+    assert!(
+        actual.contains("--coerce-by-affinity can't be combined with --schema"),
+        format!("actual: {:?}", actual)
+    );
+}
+
+#[test]
+fn reads_datetime_columns_as_dates() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open events.db
+            | get table_values
+            | nth 0
+            | get created_at.year
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "2020");
+}
+
+#[test]
+fn coerces_values_toward_their_column_affinity() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open mixed.db --raw
+            | from-sqlite --coerce-by-affinity
+            | get table_values
+            | nth 0
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(
+        actual,
+        r#"{"amount":3,"count":42,"label":"3.0","ratio":"NaN"}"#
+    );
+}
+
+#[test]
+fn coerces_reals_and_numeric_text_toward_their_column_affinity() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open mixed.db --raw
+            | from-sqlite --coerce-by-affinity
+            | get table_values
+            | nth 1
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(
+        actual,
+        r#"{"amount":2.5,"count":7,"label":"12","ratio":1.5}"#
+    );
+}
+
+#[test]
+fn keeps_integers_beyond_range_as_decimals_and_renders_reals_as_sqlite_does() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open mixed.db --raw
+            | from-sqlite --coerce-by-affinity
+            | get table_values
+            | nth 2
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(
+        actual,
+        r#"{"amount":9.223372036854776e18,"count":1,"label":"1.0e+20","ratio":0.5}"#
+    );
+}
+
+#[test]
+fn renders_reals_as_text_with_fifteen_significant_digits() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open mixed.db --raw
+            | from-sqlite --coerce-by-affinity
+            | get table_values
+            | nth 3
+            | get label
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "0.3");
+}